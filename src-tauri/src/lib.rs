@@ -1,8 +1,8 @@
 use std::{
-  collections::HashMap,
+  collections::{HashMap, VecDeque},
   fs,
-  io::{Read, Write},
-  net::{Shutdown, TcpStream, UdpSocket},
+  io::{BufRead, BufReader, Write},
+  net::{TcpStream, UdpSocket},
   path::{Component, Path},
   sync::{
     atomic::{AtomicBool, Ordering},
@@ -13,8 +13,8 @@ use std::{
 };
 
 use rosc::{encoder, OscMessage, OscPacket, OscType};
-use serde::Serialize;
-use tauri::Emitter;
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager};
 
 #[derive(Default)]
 struct X32ListenerState {
@@ -30,6 +30,48 @@ struct X32ListenerHandle {
 struct ChannelState {
   on: bool,
   fader: f32,
+  level_db: f32,
+  gate_open_at: Option<Instant>,
+  live: bool,
+}
+
+impl Default for ChannelState {
+  fn default() -> Self {
+    ChannelState {
+      on: false,
+      fader: 0.0,
+      level_db: f32::NEG_INFINITY,
+      gate_open_at: None,
+      live: false,
+    }
+  }
+}
+
+/// Metering and gating knobs for deciding when a channel counts as `live`.
+/// `metering_enabled` controls whether `/meters/1` is subscribed at all; when
+/// it's off, `live` falls back to the on/fader check alone.
+#[derive(Clone, Copy, Debug)]
+struct MeterConfig {
+  threshold: f32,
+  metering_enabled: bool,
+  gate_db: f32,
+  hold: Duration,
+}
+
+/// A channel is `live` once it's on and past the fader threshold, and (when
+/// metering is enabled) its signal is above the noise gate or was within the
+/// last `hold` duration, so brief pauses in speech don't flicker the light.
+fn channel_live(state: &ChannelState, config: &MeterConfig, now: Instant) -> bool {
+  if !(state.on && state.fader > config.threshold) {
+    return false;
+  }
+  if !config.metering_enabled {
+    return true;
+  }
+  if state.level_db > config.gate_db {
+    return true;
+  }
+  matches!(state.gate_open_at, Some(opened_at) if now.duration_since(opened_at) < config.hold)
 }
 
 #[derive(Serialize, Clone)]
@@ -37,6 +79,7 @@ struct MicChannelPayload {
   channel: u8,
   on: bool,
   fader: f32,
+  level_db: f32,
   live: bool,
 }
 
@@ -47,6 +90,25 @@ struct MicStatePayload {
   updated_at: u64,
 }
 
+/// How long the mixer has gone quiet, and whether that now counts as
+/// "disconnected" (no inbound packet for [`DISCONNECT_TIMEOUT`]).
+#[derive(Serialize, Clone)]
+struct X32ConnectionPayload {
+  connected: bool,
+  last_packet_ms: u64,
+}
+
+/// Cadence for re-sending `/subscribe` while the mixer is responding.
+const RESUBSCRIBE_INTERVAL: Duration = Duration::from_secs(8);
+/// A connection counts as lost after this long without any inbound packet —
+/// three missed subscribe cycles.
+const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(RESUBSCRIBE_INTERVAL.as_secs() * 3);
+/// Resubscribe cadence while disconnected starts fast (to catch a transient
+/// blip quickly) and backs off exponentially up to this cap, so a genuinely
+/// dead host isn't flooded forever.
+const DISCONNECT_BACKOFF_START: Duration = Duration::from_millis(250);
+const DISCONNECT_BACKOFF_CAP: Duration = Duration::from_secs(4);
+
 fn osc_arg_to_f32(arg: &OscType) -> Option<f32> {
   match arg {
     OscType::Float(value) => Some(*value),
@@ -57,15 +119,82 @@ fn osc_arg_to_f32(arg: &OscType) -> Option<f32> {
   }
 }
 
-fn parse_channel_from_addr(addr: &str) -> Option<u8> {
-  let parts: Vec<&str> = addr.split('/').collect();
-  if parts.len() < 3 {
-    return None;
+/// Which X32 parameter tree an address belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum ChannelKind {
+  Channel,
+  Bus,
+  Dca,
+  MuteGroup,
+}
+
+/// An OSC address broken into the console tree it addresses, the 1-based
+/// index within that tree, and the remaining parameter path (e.g. `mix/on`).
+#[derive(Clone, Copy, Debug)]
+struct ParsedAddress<'a> {
+  kind: ChannelKind,
+  index: u8,
+  param: &'a str,
+}
+
+/// The slice of `addr` left after dropping its first `skip` `/`-separated
+/// segments, e.g. `param_suffix("/ch/01/mix/on", 2) == "mix/on"`.
+fn param_suffix(addr: &str, skip: usize) -> &str {
+  let mut rest = addr.trim_start_matches('/');
+  for _ in 0..skip {
+    match rest.find('/') {
+      Some(pos) => rest = &rest[pos + 1..],
+      None => return "",
+    }
   }
-  if parts[1] != "ch" {
-    return None;
+  rest
+}
+
+/// Parses `/ch/NN/...`, `/bus/NN/...`, `/dca/N/...` and `/config/mute/N`
+/// addresses into their tree, index and remaining parameter path. Unknown
+/// trees (e.g. `/xinfo`, `/meters/1`) return `None`; of these only
+/// `/meters/1` has its own branch in `handle_message`, the rest are ignored.
+fn parse_x32_address(addr: &str) -> Option<ParsedAddress<'_>> {
+  let parts: Vec<&str> = addr.split('/').filter(|part| !part.is_empty()).collect();
+  match parts.as_slice() {
+    ["ch", index, ..] => Some(ParsedAddress {
+      kind: ChannelKind::Channel,
+      index: index.parse().ok()?,
+      param: param_suffix(addr, 2),
+    }),
+    ["bus", index, ..] => Some(ParsedAddress {
+      kind: ChannelKind::Bus,
+      index: index.parse().ok()?,
+      param: param_suffix(addr, 2),
+    }),
+    ["dca", index, ..] => Some(ParsedAddress {
+      kind: ChannelKind::Dca,
+      index: index.parse().ok()?,
+      param: param_suffix(addr, 2),
+    }),
+    ["config", "mute", index, ..] => Some(ParsedAddress {
+      kind: ChannelKind::MuteGroup,
+      index: index.parse().ok()?,
+      param: param_suffix(addr, 3),
+    }),
+    _ => None,
   }
-  parts[2].parse::<u8>().ok()
+}
+
+/// How the listener keeps its X32 subscriptions alive. `PerParam` issues one
+/// `/subscribe` per channel on/fader address. `XRemote` instead sends a
+/// single `/xremote` for those on the renewal timer, which makes the console
+/// stream every parameter change for ~10s — fewer subscribe packets when many
+/// channels are monitored. Meter and watch addresses are subscribed one by
+/// one under either strategy (see [`resubscribe`]). Inbound messages are not
+/// filtered against the watch addresses, so under `XRemote` every bus, DCA
+/// and mute-group change the console streams is emitted as `x32_param`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SubscriptionStrategy {
+  PerParam,
+  XRemote,
 }
 
 fn send_subscribe(socket: &UdpSocket, target: &str, address: &str, time_factor: i32) {
@@ -79,20 +208,77 @@ fn send_subscribe(socket: &UdpSocket, target: &str, address: &str, time_factor:
   }
 }
 
+fn send_xremote(socket: &UdpSocket, target: &str) {
+  let message = OscMessage {
+    addr: "/xremote".to_string(),
+    args: vec![],
+  };
+  let packet = OscPacket::Message(message);
+  if let Ok(buf) = encoder::encode(&packet) {
+    let _ = socket.send_to(&buf, target);
+  }
+}
+
+/// Renews the mixer subscriptions. `always_paths` (meter blobs, caller-supplied
+/// watch addresses) are subscribed explicitly regardless of strategy; the
+/// console streams parameter changes over `/xremote`, not metering. `param_paths` (the per-channel on/fader mix
+/// parameters) follow `strategy`: one `/subscribe` each under `PerParam`, or a
+/// single `/xremote` under `XRemote`.
+fn resubscribe(
+  socket: &UdpSocket,
+  target: &str,
+  strategy: SubscriptionStrategy,
+  param_paths: &[String],
+  always_paths: &[String],
+) {
+  for path in always_paths {
+    send_subscribe(socket, target, path, 20);
+  }
+  match strategy {
+    SubscriptionStrategy::PerParam => {
+      for path in param_paths {
+        send_subscribe(socket, target, path, 20);
+      }
+    }
+    SubscriptionStrategy::XRemote => send_xremote(socket, target),
+  }
+}
+
+/// Decodes a `/meters/1` blob: a 4-byte little-endian sample count followed
+/// by that many little-endian f32 dBFS values, one per input.
+fn decode_meter_blob(data: &[u8]) -> Vec<f32> {
+  if data.len() < 4 {
+    return Vec::new();
+  }
+  let count = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
+  let mut levels = Vec::with_capacity(count.min((data.len() - 4) / 4));
+  let mut offset = 4;
+  for _ in 0..count {
+    let Some(bytes) = data.get(offset..offset + 4) else {
+      break;
+    };
+    levels.push(f32::from_le_bytes(bytes.try_into().unwrap()));
+    offset += 4;
+  }
+  levels
+}
+
 fn emit_state(
   app: &tauri::AppHandle,
   channels: &[u8],
   states: &HashMap<u8, ChannelState>,
-  threshold: f32,
+  config: &MeterConfig,
 ) {
+  let now = Instant::now();
   let mut payload_channels = Vec::with_capacity(channels.len());
   for channel in channels {
-    let state = states.get(channel).copied().unwrap_or(ChannelState { on: false, fader: 0.0 });
-    let live = state.on && state.fader > threshold;
+    let state = states.get(channel).copied().unwrap_or_default();
+    let live = channel_live(&state, config, now);
     payload_channels.push(MicChannelPayload {
       channel: *channel,
       on: state.on,
       fader: state.fader,
+      level_db: state.level_db,
       live,
     });
   }
@@ -109,7 +295,86 @@ fn emit_state(
   let _ = app.emit("x32_mic_state", payload);
 }
 
+fn emit_connection_state(app: &tauri::AppHandle, connected: bool, since_last_packet: Duration) {
+  let payload = X32ConnectionPayload {
+    connected,
+    last_packet_ms: since_last_packet.as_millis() as u64,
+  };
+  let _ = app.emit("x32_connection", payload);
+}
+
+/// An update for any parsed address other than a channel's `mix/on` or
+/// `mix/fader` (DCA faders, mute groups, buses, other channel parameters), so
+/// the UI can monitor them without the app needing dedicated state or payload
+/// shapes for each. Addresses are not checked against the watch list; any
+/// such message the console sends is emitted.
+#[derive(Serialize, Clone)]
+struct X32ParamPayload {
+  kind: ChannelKind,
+  index: u8,
+  param: String,
+  value: f32,
+}
+
+fn emit_param_update(app: &tauri::AppHandle, parsed: &ParsedAddress, value: f32) {
+  let payload = X32ParamPayload {
+    kind: parsed.kind,
+    index: parsed.index,
+    param: parsed.param.to_string(),
+    value,
+  };
+  let _ = app.emit("x32_param", payload);
+}
+
+/// Floor on how often a meter-driven update alone is allowed to broadcast
+/// `x32_mic_state`. `/meters/1` streams continuously, so without a throttle
+/// every frame would emit a full payload; on/fader changes are comparatively
+/// rare and always emit regardless of this floor.
+const METER_EMIT_MIN_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Recomputes `live` for every tracked channel, firing any matching cue and
+/// logging any live-state edge. Returns whether any channel's `live` value
+/// changed; does not itself broadcast `x32_mic_state` — callers decide when
+/// that's warranted, since on/fader updates and meter updates have different
+/// emit cadences.
+fn update_live_states(
+  app: &tauri::AppHandle,
+  channels: &[u8],
+  states: &mut HashMap<u8, ChannelState>,
+  config: &MeterConfig,
+) -> bool {
+  let now = Instant::now();
+  let mut any_changed = false;
+  for &channel in channels {
+    let Some(entry) = states.get_mut(&channel) else {
+      continue;
+    };
+    let live = channel_live(entry, config, now);
+    if entry.live != live {
+      entry.live = live;
+      any_changed = true;
+      dispatch_cue_transition(app, channel, live);
+      record_mic_log_event(app, channel, live);
+    }
+  }
+  any_changed
+}
+
+/// Recomputes `live` and unconditionally broadcasts the refreshed state.
+/// Used after an on/fader change, where `fader`/`on` in the payload need to
+/// reach the UI even when `live` itself didn't flip.
+fn refresh_channel_live(
+  app: &tauri::AppHandle,
+  channels: &[u8],
+  states: &mut HashMap<u8, ChannelState>,
+  config: &MeterConfig,
+) {
+  update_live_states(app, channels, states, config);
+  emit_state(app, channels, states, config);
+}
+
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 fn start_x32_listener(
   app: tauri::AppHandle,
   state: tauri::State<X32ListenerState>,
@@ -117,6 +382,11 @@ fn start_x32_listener(
   port: u16,
   channels: Vec<u8>,
   threshold: f32,
+  metering_enabled: bool,
+  gate_db: f32,
+  hold_ms: u64,
+  watch_addresses: Vec<String>,
+  strategy: SubscriptionStrategy,
 ) -> Result<(), String> {
   stop_x32_listener(state.clone())?;
 
@@ -131,7 +401,7 @@ fn start_x32_listener(
   } else {
     channels
   };
-  let subscribe_paths: Vec<String> = channel_list
+  let param_paths: Vec<String> = channel_list
     .iter()
     .flat_map(|channel| {
       [
@@ -140,6 +410,18 @@ fn start_x32_listener(
       ]
     })
     .collect();
+  let mut always_paths: Vec<String> = Vec::new();
+  if metering_enabled {
+    always_paths.push("/meters/1".to_string());
+  }
+  always_paths.extend(watch_addresses);
+
+  let config = MeterConfig {
+    threshold,
+    metering_enabled,
+    gate_db,
+    hold: Duration::from_millis(hold_ms),
+  };
 
   let stop_flag = Arc::new(AtomicBool::new(false));
   let thread_stop = stop_flag.clone();
@@ -147,21 +429,53 @@ fn start_x32_listener(
 
   let handle = thread::spawn(move || {
     let mut states: HashMap<u8, ChannelState> = HashMap::new();
-    let mut last_subscribe = Instant::now() - Duration::from_secs(30);
+    let mut last_subscribe = Instant::now() - RESUBSCRIBE_INTERVAL;
+    let mut last_packet_at = Instant::now();
+    let mut connected = true;
+    let mut resubscribe_interval = RESUBSCRIBE_INTERVAL;
+    let mut last_meter_emit = Instant::now() - METER_EMIT_MIN_INTERVAL;
     let mut buf = [0u8; 2048];
 
+    // `connected` starts true with no transition to trigger the emit inside
+    // the loop below, so the UI needs this one sent up front to know where
+    // things stand from the outset.
+    emit_connection_state(&app_handle, connected, Duration::ZERO);
+
     while !thread_stop.load(Ordering::Relaxed) {
-      if last_subscribe.elapsed() >= Duration::from_secs(8) {
-        for path in &subscribe_paths {
-          send_subscribe(&socket, &target, path, 20);
+      let now = Instant::now();
+      let still_connected = now.duration_since(last_packet_at) < DISCONNECT_TIMEOUT;
+      if still_connected != connected {
+        connected = still_connected;
+        if connected {
+          resubscribe_interval = RESUBSCRIBE_INTERVAL;
+          resubscribe(&socket, &target, strategy, &param_paths, &always_paths);
+          last_subscribe = now;
+        } else {
+          resubscribe_interval = DISCONNECT_BACKOFF_START;
         }
+        emit_connection_state(&app_handle, connected, now.duration_since(last_packet_at));
+      }
+
+      if last_subscribe.elapsed() >= resubscribe_interval {
+        resubscribe(&socket, &target, strategy, &param_paths, &always_paths);
         last_subscribe = Instant::now();
+        if !connected {
+          resubscribe_interval = (resubscribe_interval * 2).min(DISCONNECT_BACKOFF_CAP);
+        }
       }
 
       match socket.recv_from(&mut buf) {
         Ok((size, _)) => {
+          last_packet_at = Instant::now();
           if let Ok((_, packet)) = rosc::decoder::decode_udp(&buf[..size]) {
-            handle_packet(&app_handle, &channel_list, &mut states, threshold, packet);
+            handle_packet(
+              &app_handle,
+              &channel_list,
+              &mut states,
+              &config,
+              &mut last_meter_emit,
+              packet,
+            );
           }
         }
         Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {}
@@ -183,32 +497,91 @@ fn handle_packet(
   app: &tauri::AppHandle,
   channels: &[u8],
   states: &mut HashMap<u8, ChannelState>,
-  threshold: f32,
+  config: &MeterConfig,
+  last_meter_emit: &mut Instant,
   packet: OscPacket,
 ) {
   match packet {
-    OscPacket::Message(message) => handle_message(app, channels, states, threshold, message),
+    OscPacket::Message(message) => {
+      handle_message(app, channels, states, config, last_meter_emit, message)
+    }
     OscPacket::Bundle(bundle) => {
       for entry in bundle.content {
-        handle_packet(app, channels, states, threshold, entry);
+        handle_packet(app, channels, states, config, last_meter_emit, entry);
       }
     }
   }
 }
 
+fn handle_meter_message(
+  app: &tauri::AppHandle,
+  channels: &[u8],
+  states: &mut HashMap<u8, ChannelState>,
+  config: &MeterConfig,
+  last_meter_emit: &mut Instant,
+  message: &OscMessage,
+) {
+  let levels = match message.args.first() {
+    Some(OscType::Blob(bytes)) => decode_meter_blob(bytes),
+    _ => return,
+  };
+
+  let now = Instant::now();
+  let mut changed = false;
+  for &channel in channels {
+    let Some(index) = (channel as usize).checked_sub(1) else {
+      continue;
+    };
+    let Some(&raw_level) = levels.get(index) else {
+      continue;
+    };
+    let entry = states.entry(channel).or_insert_with(ChannelState::default);
+    entry.level_db = if entry.level_db.is_finite() {
+      entry.level_db * 0.7 + raw_level * 0.3
+    } else {
+      raw_level
+    };
+    if entry.level_db > config.gate_db {
+      entry.gate_open_at = Some(now);
+    }
+    changed = true;
+  }
+
+  if changed {
+    let live_changed = update_live_states(app, channels, states, config);
+    if live_changed || last_meter_emit.elapsed() >= METER_EMIT_MIN_INTERVAL {
+      emit_state(app, channels, states, config);
+      *last_meter_emit = now;
+    }
+  }
+}
+
 fn handle_message(
   app: &tauri::AppHandle,
   channels: &[u8],
   states: &mut HashMap<u8, ChannelState>,
-  threshold: f32,
+  config: &MeterConfig,
+  last_meter_emit: &mut Instant,
   message: OscMessage,
 ) {
-  let channel = match parse_channel_from_addr(&message.addr) {
-    Some(channel) => channel,
-    None => return,
+  if config.metering_enabled && message.addr == "/meters/1" {
+    handle_meter_message(app, channels, states, config, last_meter_emit, &message);
+    return;
+  }
+
+  let Some(parsed) = parse_x32_address(&message.addr) else {
+    return;
   };
 
-  if message.addr.ends_with("/mix/on") {
+  if parsed.kind != ChannelKind::Channel {
+    if let Some(arg) = message.args.first().and_then(osc_arg_to_f32) {
+      emit_param_update(app, &parsed, arg);
+    }
+    return;
+  }
+  let channel = parsed.index;
+
+  if parsed.param == "mix/on" {
     if let Some(arg) = message.args.first() {
       let on = match arg {
         OscType::Int(value) => *value != 0,
@@ -217,22 +590,28 @@ fn handle_message(
         OscType::Double(value) => *value > 0.0,
         _ => false,
       };
-      let entry = states.entry(channel).or_insert(ChannelState { on: false, fader: 0.0 });
+      let entry = states.entry(channel).or_insert_with(ChannelState::default);
       if entry.on != on {
         entry.on = on;
-        emit_state(app, channels, states, threshold);
+        refresh_channel_live(app, channels, states, config);
       }
     }
+    return;
   }
 
-  if message.addr.ends_with("/mix/fader") {
+  if parsed.param == "mix/fader" {
     if let Some(arg) = message.args.first().and_then(osc_arg_to_f32) {
-      let entry = states.entry(channel).or_insert(ChannelState { on: false, fader: 0.0 });
+      let entry = states.entry(channel).or_insert_with(ChannelState::default);
       if (entry.fader - arg).abs() > f32::EPSILON {
         entry.fader = arg;
-        emit_state(app, channels, states, threshold);
+        refresh_channel_live(app, channels, states, config);
       }
     }
+    return;
+  }
+
+  if let Some(arg) = message.args.first().and_then(osc_arg_to_f32) {
+    emit_param_update(app, &parsed, arg);
   }
 }
 
@@ -263,40 +642,182 @@ fn escape_amcp_quoted(value: &str) -> String {
   value.replace('\\', "\\\\").replace('\"', "\\\"")
 }
 
-fn send_amcp(host: &str, port: u16, command: &str) -> Result<String, String> {
-  let address = format!("{}:{}", host.trim(), port);
-  let mut stream = TcpStream::connect(address).map_err(|err| err.to_string())?;
+/// A parsed AMCP reply: the numeric status code, the raw status line, and any
+/// data lines that followed it (populated for 200/201 responses).
+#[derive(Serialize, Clone, Debug)]
+struct AmcpResponse {
+  code: u16,
+  text: String,
+  lines: Vec<String>,
+}
+
+/// Distinguishes three transport outcomes that `send_amcp_managed` needs to
+/// treat differently:
+/// - `Io`: the failure happened sending the command (or the connection was
+///   already dead before we tried) — nothing was delivered, so retrying on a
+///   fresh connection is safe.
+/// - `ReplyTimeout`: the command was already written and may have executed,
+///   but the reply never arrived. Resending here could double-fire a
+///   non-idempotent verb like `CG ADD`, so this must surface as an error
+///   instead of triggering a retry.
+/// - `Protocol`: the command was rejected by CasparCG itself; a fresh
+///   connection would not help.
+enum AmcpTransportError {
+  Io(String),
+  ReplyTimeout(String),
+  Protocol(String),
+}
+
+impl AmcpTransportError {
+  fn into_message(self) -> String {
+    match self {
+      AmcpTransportError::Io(message) => message,
+      AmcpTransportError::ReplyTimeout(message) => message,
+      AmcpTransportError::Protocol(message) => message,
+    }
+  }
+}
+
+fn write_amcp_command(stream: &TcpStream, command: &str) -> Result<(), AmcpTransportError> {
+  let payload = format!("{}\r\n", command.trim());
+  (&*stream)
+    .write_all(payload.as_bytes())
+    .map_err(|err| AmcpTransportError::Io(err.to_string()))
+}
+
+/// Reads and parses a single AMCP reply from `stream`.
+///
+/// `200` replies are followed by a data block terminated by a blank line,
+/// `201` replies are followed by exactly one data line, `202` has no data,
+/// and any `4xx`/`5xx` status is a protocol-level error.
+fn read_amcp_response(stream: &TcpStream) -> Result<AmcpResponse, AmcpTransportError> {
+  let mut reader = BufReader::new(stream);
+  let mut first_line = String::new();
+  reader
+    .read_line(&mut first_line)
+    .map_err(|err| AmcpTransportError::ReplyTimeout(err.to_string()))?;
+  if first_line.is_empty() {
+    return Err(AmcpTransportError::ReplyTimeout(
+      "AMCP connection closed before a response was received".to_string(),
+    ));
+  }
+  let trimmed_first = first_line.trim_end_matches(['\r', '\n']).to_string();
+  let code: u16 = trimmed_first
+    .get(0..3)
+    .and_then(|digits| digits.parse().ok())
+    .ok_or_else(|| {
+      AmcpTransportError::Protocol(format!("Malformed AMCP response: {trimmed_first}"))
+    })?;
+
+  let mut lines = Vec::new();
+  match code {
+    200 => loop {
+      let mut line = String::new();
+      let read = reader
+        .read_line(&mut line)
+        .map_err(|err| AmcpTransportError::ReplyTimeout(err.to_string()))?;
+      if read == 0 {
+        break;
+      }
+      let line = line.trim_end_matches(['\r', '\n']).to_string();
+      if line.is_empty() {
+        break;
+      }
+      lines.push(line);
+    },
+    201 => {
+      let mut line = String::new();
+      reader
+        .read_line(&mut line)
+        .map_err(|err| AmcpTransportError::ReplyTimeout(err.to_string()))?;
+      lines.push(line.trim_end_matches(['\r', '\n']).to_string());
+    }
+    202 => {}
+    400..=599 => {
+      return Err(AmcpTransportError::Protocol(format!(
+        "AMCP error {code}: {trimmed_first}"
+      )))
+    }
+    _ => {}
+  }
+
+  Ok(AmcpResponse {
+    code,
+    text: trimmed_first,
+    lines,
+  })
+}
+
+fn send_amcp_on_stream(stream: &TcpStream, command: &str) -> Result<AmcpResponse, AmcpTransportError> {
+  write_amcp_command(stream, command)?;
+  read_amcp_response(stream)
+}
+
+fn connect_amcp(address: &str) -> Result<TcpStream, String> {
+  let stream = TcpStream::connect(address).map_err(|err| err.to_string())?;
   stream
     .set_read_timeout(Some(Duration::from_millis(1200)))
     .map_err(|err| err.to_string())?;
   stream
     .set_write_timeout(Some(Duration::from_millis(1200)))
     .map_err(|err| err.to_string())?;
+  Ok(stream)
+}
 
-  let payload = format!("{}\r\n", command.trim());
-  stream.write_all(payload.as_bytes()).map_err(|err| err.to_string())?;
-  let _ = stream.shutdown(Shutdown::Write);
+fn send_amcp(host: &str, port: u16, command: &str) -> Result<AmcpResponse, String> {
+  let address = format!("{}:{}", host.trim(), port);
+  let stream = connect_amcp(&address)?;
+  send_amcp_on_stream(&stream, command).map_err(AmcpTransportError::into_message)
+}
 
-  let mut response = String::new();
-  stream
-    .read_to_string(&mut response)
-    .map_err(|err| err.to_string())?;
+/// Holds one keep-alive `TcpStream` per `host:port`, reused across
+/// `casparcg_play_template`/`update`/`stop` calls instead of reconnecting for
+/// every command. A connection that turns out to be broken on the write side
+/// (e.g. the peer reset it) is dropped, and the command is resent once on a
+/// fresh connection within the same call. A reply that never arrives after a successful write is NOT retried
+/// here — the command may already have executed, and resending a non-
+/// idempotent verb like `CG ADD` would double-fire it.
+#[derive(Default)]
+struct CasparGcState {
+  connections: Mutex<HashMap<String, TcpStream>>,
+}
 
-  let trimmed = response.trim().to_string();
-  if trimmed.is_empty() {
-    Ok("No response".to_string())
-  } else {
-    Ok(trimmed)
+fn send_amcp_managed(
+  state: &CasparGcState,
+  host: &str,
+  port: u16,
+  command: &str,
+) -> Result<AmcpResponse, String> {
+  let key = format!("{}:{}", host.trim(), port);
+  let mut connections = state
+    .connections
+    .lock()
+    .map_err(|_| "CasparCG connection lock poisoned".to_string())?;
+
+  if let Some(stream) = connections.get(&key) {
+    match send_amcp_on_stream(stream, command) {
+      Ok(response) => return Ok(response),
+      Err(AmcpTransportError::Protocol(message)) => return Err(message),
+      Err(AmcpTransportError::ReplyTimeout(message)) => return Err(message),
+      Err(AmcpTransportError::Io(_)) => {
+        connections.remove(&key);
+      }
+    }
   }
+
+  let stream = connect_amcp(&key)?;
+  let response = send_amcp_on_stream(&stream, command).map_err(AmcpTransportError::into_message)?;
+  connections.insert(key, stream);
+  Ok(response)
 }
 
 #[tauri::command]
-fn casparcg_ping(host: String, port: u16) -> Result<String, String> {
+fn casparcg_ping(host: String, port: u16) -> Result<AmcpResponse, String> {
   send_amcp(host.trim(), port, "INFO")
 }
 
 #[tauri::command]
-fn casparcg_send_amcp(host: String, port: u16, command: String) -> Result<String, String> {
+fn casparcg_send_amcp(host: String, port: u16, command: String) -> Result<AmcpResponse, String> {
   let clean = sanitize_amcp_value(&command)?;
   if clean.is_empty() {
     return Err("Command is required".to_string());
@@ -304,48 +825,201 @@ fn casparcg_send_amcp(host: String, port: u16, command: String) -> Result<String
   send_amcp(host.trim(), port, &clean)
 }
 
-#[tauri::command]
-fn casparcg_play_template(
-  host: String,
-  port: u16,
-  channel: u16,
-  layer: u16,
-  template: String,
-  data: String,
-) -> Result<String, String> {
-  let clean_template = sanitize_amcp_value(&template)?;
+fn play_template_command(channel: u16, layer: u16, template: &str, data: &str) -> Result<String, String> {
+  let clean_template = sanitize_amcp_value(template)?;
   if clean_template.is_empty() {
     return Err("Template name is required".to_string());
   }
-  let clean_data = sanitize_amcp_value(&data)?;
+  let clean_data = sanitize_amcp_value(data)?;
   let escaped_template = escape_amcp_quoted(&clean_template);
   let escaped_data = escape_amcp_quoted(&clean_data);
-  let command = if clean_data.is_empty() {
+  Ok(if clean_data.is_empty() {
     format!("CG {}-{} ADD 1 \"{}\" 1", channel, layer, escaped_template)
   } else {
     format!("CG {}-{} ADD 1 \"{}\" 1 \"{}\"", channel, layer, escaped_template, escaped_data)
-  };
-  send_amcp(host.trim(), port, &command)
+  })
+}
+
+fn update_template_command(channel: u16, layer: u16, data: &str) -> Result<String, String> {
+  let clean_data = sanitize_amcp_value(data)?;
+  let escaped_data = escape_amcp_quoted(&clean_data);
+  Ok(format!("CG {}-{} UPDATE 1 \"{}\"", channel, layer, escaped_data))
+}
+
+fn stop_template_command(channel: u16, layer: u16) -> String {
+  format!("CG {}-{} STOP 1", channel, layer)
+}
+
+#[tauri::command]
+fn casparcg_play_template(
+  state: tauri::State<CasparGcState>,
+  host: String,
+  port: u16,
+  channel: u16,
+  layer: u16,
+  template: String,
+  data: String,
+) -> Result<AmcpResponse, String> {
+  let command = play_template_command(channel, layer, &template, &data)?;
+  send_amcp_managed(&state, host.trim(), port, &command)
 }
 
 #[tauri::command]
 fn casparcg_update_template(
+  state: tauri::State<CasparGcState>,
   host: String,
   port: u16,
   channel: u16,
   layer: u16,
   data: String,
-) -> Result<String, String> {
-  let clean_data = sanitize_amcp_value(&data)?;
-  let escaped_data = escape_amcp_quoted(&clean_data);
-  let command = format!("CG {}-{} UPDATE 1 \"{}\"", channel, layer, escaped_data);
-  send_amcp(host.trim(), port, &command)
+) -> Result<AmcpResponse, String> {
+  let command = update_template_command(channel, layer, &data)?;
+  send_amcp_managed(&state, host.trim(), port, &command)
+}
+
+#[tauri::command]
+fn casparcg_stop_template(
+  state: tauri::State<CasparGcState>,
+  host: String,
+  port: u16,
+  channel: u16,
+  layer: u16,
+) -> Result<AmcpResponse, String> {
+  let command = stop_template_command(channel, layer);
+  send_amcp_managed(&state, host.trim(), port, &command)
+}
+
+/// One trigger condition a [`Cue`] can react to. Kept as an enum (rather than
+/// just "live") so new reactive conditions can be added later without
+/// changing the shape of stored cues.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum Trigger {
+  Live,
+}
+
+/// An AMCP side effect a cue can run when it fires.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum AmcpAction {
+  PlayTemplate {
+    host: String,
+    port: u16,
+    channel: u16,
+    layer: u16,
+    template: String,
+    data: String,
+  },
+  UpdateTemplate {
+    host: String,
+    port: u16,
+    channel: u16,
+    layer: u16,
+    data: String,
+  },
+  StopTemplate {
+    host: String,
+    port: u16,
+    channel: u16,
+    layer: u16,
+  },
+  RawAmcp {
+    host: String,
+    port: u16,
+    command: String,
+  },
+}
+
+/// A reactive rule: when `channel` crosses `trigger`, run `on_enter`; when it
+/// stops matching, run `on_exit`. Modeled after dataspace-style assertions —
+/// the cue reacts to the *edge*, not the level-triggered state itself.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Cue {
+  channel: u8,
+  trigger: Trigger,
+  on_enter: AmcpAction,
+  on_exit: AmcpAction,
+}
+
+#[derive(Serialize, Clone)]
+struct CueFiredPayload {
+  channel: u8,
+  live: bool,
+  ok: bool,
+  message: String,
+}
+
+/// Cues registered by the UI. Looked up by channel whenever the listener
+/// thread detects a live-state edge.
+#[derive(Default)]
+struct CueState {
+  cues: Mutex<Vec<Cue>>,
 }
 
 #[tauri::command]
-fn casparcg_stop_template(host: String, port: u16, channel: u16, layer: u16) -> Result<String, String> {
-  let command = format!("CG {}-{} STOP 1", channel, layer);
-  send_amcp(host.trim(), port, &command)
+fn set_cues(state: tauri::State<CueState>, cues: Vec<Cue>) -> Result<(), String> {
+  let mut guard = state.cues.lock().map_err(|_| "Cue lock poisoned".to_string())?;
+  *guard = cues;
+  Ok(())
+}
+
+#[tauri::command]
+fn clear_cues(state: tauri::State<CueState>) -> Result<(), String> {
+  let mut guard = state.cues.lock().map_err(|_| "Cue lock poisoned".to_string())?;
+  guard.clear();
+  Ok(())
+}
+
+fn execute_amcp_action(app: &tauri::AppHandle, action: &AmcpAction) -> Result<AmcpResponse, String> {
+  let caspar = app.state::<CasparGcState>();
+  match action {
+    AmcpAction::PlayTemplate { host, port, channel, layer, template, data } => {
+      let command = play_template_command(*channel, *layer, template, data)?;
+      send_amcp_managed(&caspar, host.trim(), *port, &command)
+    }
+    AmcpAction::UpdateTemplate { host, port, channel, layer, data } => {
+      let command = update_template_command(*channel, *layer, data)?;
+      send_amcp_managed(&caspar, host.trim(), *port, &command)
+    }
+    AmcpAction::StopTemplate { host, port, channel, layer } => {
+      let command = stop_template_command(*channel, *layer);
+      send_amcp_managed(&caspar, host.trim(), *port, &command)
+    }
+    AmcpAction::RawAmcp { host, port, command } => {
+      let clean = sanitize_amcp_value(command)?;
+      if clean.is_empty() {
+        return Err("Command is required".to_string());
+      }
+      send_amcp_managed(&caspar, host.trim(), *port, &clean)
+    }
+  }
+}
+
+/// Looks up cues for `channel`, and for each, fires `on_enter`/`on_exit`
+/// (depending on `live`) through `send_amcp_managed` on its own worker thread so a
+/// slow or unreachable CasparCG server never stalls OSC reception.
+fn dispatch_cue_transition(app: &tauri::AppHandle, channel: u8, live: bool) {
+  let cues = app.state::<CueState>();
+  let matching: Vec<Cue> = match cues.cues.lock() {
+    Ok(guard) => guard
+      .iter()
+      .filter(|cue| cue.channel == channel && cue.trigger == Trigger::Live)
+      .cloned()
+      .collect(),
+    Err(_) => return,
+  };
+
+  for cue in matching {
+    let action = if live { cue.on_enter } else { cue.on_exit };
+    let app_handle = app.clone();
+    thread::spawn(move || {
+      let result = execute_amcp_action(&app_handle, &action);
+      let payload = match result {
+        Ok(response) => CueFiredPayload { channel, live, ok: true, message: response.text },
+        Err(message) => CueFiredPayload { channel, live, ok: false, message },
+      };
+      let _ = app_handle.emit("cue_fired", payload);
+    });
+  }
 }
 
 fn validate_relative_template_path(path: &str) -> Result<String, String> {
@@ -387,6 +1061,146 @@ fn casparcg_write_template_file(
   Ok(format!("Wrote {}", target.display()))
 }
 
+/// Max events kept in memory for `export_mic_sessions`; the append-only file
+/// is the durable record, the ring is just what a running show can export.
+const MIC_LOG_RING_CAPACITY: usize = 10_000;
+
+fn sanitize_log_path(path: &str) -> Result<String, String> {
+  let trimmed = path.trim();
+  if trimmed.is_empty() {
+    return Err("Log file path is required".to_string());
+  }
+  if trimmed.chars().any(|ch| ch == '\n' || ch == '\r') {
+    return Err("Log file path cannot contain line breaks".to_string());
+  }
+  Ok(trimmed.to_string())
+}
+
+#[derive(Clone, Copy, Debug)]
+struct MicLogEvent {
+  channel: u8,
+  live: bool,
+  timestamp_ms: u64,
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+struct MicSession {
+  channel: u8,
+  start_ms: u64,
+  end_ms: u64,
+  duration_ms: u64,
+}
+
+struct MicLogHandle {
+  file: fs::File,
+  ring: VecDeque<MicLogEvent>,
+}
+
+/// Records every live-state edge to an append-only file plus an in-memory
+/// ring, so `export_mic_sessions` can later report total on-air time per mic.
+#[derive(Default)]
+struct MicLogState {
+  inner: Mutex<Option<MicLogHandle>>,
+}
+
+#[tauri::command]
+fn start_mic_log(state: tauri::State<MicLogState>, path: String) -> Result<(), String> {
+  let clean_path = sanitize_log_path(&path)?;
+  let file = fs::OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(&clean_path)
+    .map_err(|err| err.to_string())?;
+
+  let mut guard = state.inner.lock().map_err(|_| "Mic log lock poisoned".to_string())?;
+  *guard = Some(MicLogHandle {
+    file,
+    ring: VecDeque::new(),
+  });
+  Ok(())
+}
+
+#[tauri::command]
+fn stop_mic_log(state: tauri::State<MicLogState>) -> Result<(), String> {
+  let mut guard = state.inner.lock().map_err(|_| "Mic log lock poisoned".to_string())?;
+  *guard = None;
+  Ok(())
+}
+
+fn record_mic_log_event(app: &tauri::AppHandle, channel: u8, live: bool) {
+  let state = app.state::<MicLogState>();
+  let mut guard = match state.inner.lock() {
+    Ok(guard) => guard,
+    Err(_) => return,
+  };
+  let Some(handle) = guard.as_mut() else {
+    return;
+  };
+
+  let timestamp_ms = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or(0);
+  let event = MicLogEvent { channel, live, timestamp_ms };
+
+  let line = format!("{},{},{}\n", event.channel, event.live, event.timestamp_ms);
+  let _ = handle.file.write_all(line.as_bytes());
+
+  if handle.ring.len() >= MIC_LOG_RING_CAPACITY {
+    handle.ring.pop_front();
+  }
+  handle.ring.push_back(event);
+}
+
+/// Collapses a stream of live-state edges into `(start, end)` sessions per
+/// channel. An edge that opens a session without a matching close (the mic
+/// was still live when the log ended) is left out.
+fn collapse_mic_sessions(events: &[MicLogEvent]) -> Vec<MicSession> {
+  let mut open: HashMap<u8, u64> = HashMap::new();
+  let mut sessions = Vec::new();
+  for event in events {
+    if event.live {
+      open.insert(event.channel, event.timestamp_ms);
+    } else if let Some(start_ms) = open.remove(&event.channel) {
+      sessions.push(MicSession {
+        channel: event.channel,
+        start_ms,
+        end_ms: event.timestamp_ms,
+        duration_ms: event.timestamp_ms.saturating_sub(start_ms),
+      });
+    }
+  }
+  sessions
+}
+
+fn mic_sessions_to_csv(sessions: &[MicSession]) -> String {
+  let mut output = String::from("channel,start_ms,end_ms,duration_ms\n");
+  for session in sessions {
+    output.push_str(&format!(
+      "{},{},{},{}\n",
+      session.channel, session.start_ms, session.end_ms, session.duration_ms
+    ));
+  }
+  output
+}
+
+#[tauri::command]
+fn export_mic_sessions(state: tauri::State<MicLogState>, format: String) -> Result<String, String> {
+  let guard = state.inner.lock().map_err(|_| "Mic log lock poisoned".to_string())?;
+  let events: Vec<MicLogEvent> = guard
+    .as_ref()
+    .map(|handle| handle.ring.iter().copied().collect())
+    .unwrap_or_default();
+  drop(guard);
+
+  let sessions = collapse_mic_sessions(&events);
+  match format.trim().to_lowercase().as_str() {
+    "csv" => Ok(mic_sessions_to_csv(&sessions)),
+    "json" => serde_json::to_string(&sessions).map_err(|err| err.to_string()),
+    other => Err(format!("Unsupported export format: {other}")),
+  }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   tauri::Builder::default()
@@ -402,6 +1216,9 @@ pub fn run() {
     })
     .plugin(tauri_plugin_updater::Builder::new().build())
     .manage(X32ListenerState::default())
+    .manage(CasparGcState::default())
+    .manage(CueState::default())
+    .manage(MicLogState::default())
     .invoke_handler(tauri::generate_handler![
       start_x32_listener,
       stop_x32_listener,
@@ -410,8 +1227,193 @@ pub fn run() {
       casparcg_play_template,
       casparcg_update_template,
       casparcg_stop_template,
-      casparcg_write_template_file
+      casparcg_write_template_file,
+      set_cues,
+      clear_cues,
+      start_mic_log,
+      stop_mic_log,
+      export_mic_sessions
     ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::net::TcpListener;
+
+  fn amcp_roundtrip(reply: &'static str) -> Result<AmcpResponse, AmcpTransportError> {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = thread::spawn(move || {
+      let (mut stream, _) = listener.accept().unwrap();
+      stream.write_all(reply.as_bytes()).unwrap();
+    });
+    let client = TcpStream::connect(addr).unwrap();
+    let response = read_amcp_response(&client);
+    server.join().unwrap();
+    response
+  }
+
+  #[test]
+  fn reads_200_with_data_block() {
+    let response = amcp_roundtrip("200 OK\r\nline one\r\nline two\r\n\r\n").unwrap();
+    assert_eq!(response.code, 200);
+    assert_eq!(response.lines, vec!["line one".to_string(), "line two".to_string()]);
+  }
+
+  #[test]
+  fn reads_201_with_single_line() {
+    let response = amcp_roundtrip("201 INFO OK\r\nsome data\r\n").unwrap();
+    assert_eq!(response.code, 201);
+    assert_eq!(response.lines, vec!["some data".to_string()]);
+  }
+
+  #[test]
+  fn reads_202_with_no_data() {
+    let response = amcp_roundtrip("202 PLAY OK\r\n").unwrap();
+    assert_eq!(response.code, 202);
+    assert!(response.lines.is_empty());
+  }
+
+  #[test]
+  fn treats_403_as_protocol_error() {
+    let err = amcp_roundtrip("403 PLAY FAILED\r\n").unwrap_err();
+    assert!(matches!(err, AmcpTransportError::Protocol(_)));
+  }
+
+  #[test]
+  fn treats_501_as_protocol_error() {
+    let err = amcp_roundtrip("501 PLAY FAILED\r\n").unwrap_err();
+    assert!(matches!(err, AmcpTransportError::Protocol(_)));
+  }
+
+  #[test]
+  fn malformed_status_line_is_protocol_error() {
+    let err = amcp_roundtrip("nope\r\n").unwrap_err();
+    assert!(matches!(err, AmcpTransportError::Protocol(_)));
+  }
+
+  fn meter_config(metering_enabled: bool) -> MeterConfig {
+    MeterConfig {
+      threshold: 0.1,
+      metering_enabled,
+      gate_db: -40.0,
+      hold: Duration::from_millis(500),
+    }
+  }
+
+  #[test]
+  fn channel_live_requires_on_and_fader_above_threshold() {
+    let config = meter_config(false);
+    let now = Instant::now();
+    let mut state = ChannelState::default();
+    assert!(!channel_live(&state, &config, now));
+    state.on = true;
+    assert!(!channel_live(&state, &config, now));
+    state.fader = 0.5;
+    assert!(channel_live(&state, &config, now));
+  }
+
+  #[test]
+  fn channel_live_gates_on_meter_level_when_metering_enabled() {
+    let config = meter_config(true);
+    let now = Instant::now();
+    let mut state = ChannelState { on: true, fader: 0.5, level_db: -60.0, gate_open_at: None, live: false };
+    assert!(!channel_live(&state, &config, now));
+    state.level_db = -10.0;
+    assert!(channel_live(&state, &config, now));
+  }
+
+  #[test]
+  fn channel_live_holds_open_after_level_drops() {
+    let config = meter_config(true);
+    let now = Instant::now();
+    let state = ChannelState { on: true, fader: 0.5, level_db: -60.0, gate_open_at: Some(now), live: false };
+    assert!(channel_live(&state, &config, now));
+  }
+
+  #[test]
+  fn decode_meter_blob_reads_declared_count() {
+    let mut data = Vec::new();
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&1.5f32.to_le_bytes());
+    data.extend_from_slice(&(-3.25f32).to_le_bytes());
+    assert_eq!(decode_meter_blob(&data), vec![1.5, -3.25]);
+  }
+
+  #[test]
+  fn decode_meter_blob_truncated_blob_stops_early() {
+    let mut data = Vec::new();
+    data.extend_from_slice(&5u32.to_le_bytes());
+    data.extend_from_slice(&1.0f32.to_le_bytes());
+    assert_eq!(decode_meter_blob(&data), vec![1.0]);
+  }
+
+  #[test]
+  fn decode_meter_blob_too_short_for_header_is_empty() {
+    assert!(decode_meter_blob(&[0u8; 2]).is_empty());
+  }
+
+  #[test]
+  fn collapse_mic_sessions_pairs_live_and_quiet_edges() {
+    let events = vec![
+      MicLogEvent { channel: 1, live: true, timestamp_ms: 1000 },
+      MicLogEvent { channel: 1, live: false, timestamp_ms: 1500 },
+    ];
+    let sessions = collapse_mic_sessions(&events);
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].duration_ms, 500);
+  }
+
+  #[test]
+  fn collapse_mic_sessions_drops_unterminated_session() {
+    let events = vec![MicLogEvent { channel: 2, live: true, timestamp_ms: 2000 }];
+    assert!(collapse_mic_sessions(&events).is_empty());
+  }
+
+  #[test]
+  fn collapse_mic_sessions_tracks_channels_independently() {
+    let events = vec![
+      MicLogEvent { channel: 1, live: true, timestamp_ms: 0 },
+      MicLogEvent { channel: 2, live: true, timestamp_ms: 10 },
+      MicLogEvent { channel: 1, live: false, timestamp_ms: 20 },
+      MicLogEvent { channel: 2, live: false, timestamp_ms: 40 },
+    ];
+    let sessions = collapse_mic_sessions(&events);
+    assert_eq!(sessions.len(), 2);
+  }
+
+  #[test]
+  fn parses_channel_address() {
+    let parsed = parse_x32_address("/ch/01/mix/fader").unwrap();
+    assert_eq!(parsed.kind, ChannelKind::Channel);
+    assert_eq!(parsed.index, 1);
+    assert_eq!(parsed.param, "mix/fader");
+  }
+
+  #[test]
+  fn parses_mute_group_address() {
+    let parsed = parse_x32_address("/config/mute/3").unwrap();
+    assert_eq!(parsed.kind, ChannelKind::MuteGroup);
+    assert_eq!(parsed.index, 3);
+    assert_eq!(parsed.param, "");
+  }
+
+  #[test]
+  fn rejects_unknown_tree() {
+    assert!(parse_x32_address("/xinfo").is_none());
+  }
+
+  #[test]
+  fn rejects_non_numeric_index() {
+    assert!(parse_x32_address("/ch/aa/mix/on").is_none());
+  }
+
+  #[test]
+  fn param_suffix_strips_leading_segments() {
+    assert_eq!(param_suffix("/ch/01/mix/on", 2), "mix/on");
+    assert_eq!(param_suffix("/bus/3", 2), "");
+  }
+}